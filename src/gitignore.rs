@@ -0,0 +1,184 @@
+use crate::utils::glob_match_inner;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A single parsed line from a `.gitignore`-style file.
+struct IgnorePattern {
+    glob: String,
+    negate: bool,
+    dir_only: bool,
+    anchored: bool,
+}
+
+/// One ignore file's patterns, scoped to the directory it was found in.
+/// Anchored patterns are matched relative to `dir`, not the search root —
+/// the same way git resolves a nested `.gitignore`.
+struct Frame {
+    dir: PathBuf,
+    patterns: Vec<IgnorePattern>,
+}
+
+/// A stack of ignore frames, one per directory level, inherited by
+/// subdirectories the way `.gitignore` rules cascade in git.
+pub struct IgnoreStack {
+    frames: Vec<Frame>,
+}
+
+impl IgnoreStack {
+    pub fn new() -> Self {
+        IgnoreStack { frames: Vec::new() }
+    }
+
+    /// Read any `.gitignore`, `.ignore`, and `.frignore` in `dir` and push a
+    /// new frame for them. Always push (even if empty) so `pop` stays balanced.
+    pub fn push_dir(&mut self, dir: &Path, honor_vcs: bool, honor_other: bool) {
+        let mut patterns = Vec::new();
+
+        if honor_vcs {
+            patterns.extend(Self::read_file(&dir.join(".gitignore")));
+        }
+        if honor_other {
+            patterns.extend(Self::read_file(&dir.join(".ignore")));
+            patterns.extend(Self::read_file(&dir.join(".frignore")));
+        }
+
+        self.frames.push(Frame { dir: dir.to_path_buf(), patterns });
+    }
+
+    pub fn pop(&mut self) {
+        self.frames.pop();
+    }
+
+    fn read_file(path: &Path) -> Vec<IgnorePattern> {
+        let Ok(contents) = fs::read_to_string(path) else {
+            return Vec::new();
+        };
+
+        contents
+            .lines()
+            .filter_map(Self::parse_line)
+            .collect()
+    }
+
+    fn parse_line(line: &str) -> Option<IgnorePattern> {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let mut rest = line;
+        let negate = rest.starts_with('!');
+        if negate {
+            rest = &rest[1..];
+        }
+
+        let dir_only = rest.ends_with('/');
+        if dir_only {
+            rest = &rest[..rest.len() - 1];
+        }
+
+        // A pattern is anchored to its directory if it contains a `/`
+        // anywhere but the trailing position (already stripped above).
+        let anchored = rest.contains('/');
+        let glob = rest.trim_start_matches('/').to_string();
+
+        if glob.is_empty() {
+            return None;
+        }
+
+        Some(IgnorePattern {
+            glob,
+            negate,
+            dir_only,
+            anchored,
+        })
+    }
+
+    /// Does `path` match an ignore rule? `is_dir` gates directory-only
+    /// patterns. Anchored patterns are matched against `path` relative to
+    /// the directory each frame was read from (not the search root), since
+    /// that's what anchoring means in a nested `.gitignore`/`.ignore`.
+    pub fn is_ignored(&self, path: &Path, file_name: &str, is_dir: bool) -> bool {
+        let mut ignored = false;
+
+        for frame in &self.frames {
+            for pattern in &frame.patterns {
+                if pattern.dir_only && !is_dir {
+                    continue;
+                }
+
+                if pattern.anchored {
+                    let Ok(rel_path) = path.strip_prefix(&frame.dir) else { continue };
+                    let rel_path = rel_path.to_string_lossy().replace('\\', "/");
+                    if glob_match(&pattern.glob, &rel_path) {
+                        ignored = !pattern.negate;
+                    }
+                } else if glob_match(&pattern.glob, file_name) {
+                    ignored = !pattern.negate;
+                }
+            }
+        }
+
+        ignored
+    }
+}
+
+/// Shell-style glob match supporting `*` and `?`, anchored to the full string.
+fn glob_match(pattern: &str, candidate: &str) -> bool {
+    glob_match_inner(pattern.as_bytes(), candidate.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn anchored_pattern_matches_relative_to_its_own_frame() {
+        let mut stack = IgnoreStack::new();
+        stack.frames.push(Frame {
+            dir: PathBuf::from("/repo/sub"),
+            patterns: vec![IgnorePattern {
+                glob: "dir/file.txt".to_string(),
+                negate: false,
+                dir_only: false,
+                anchored: true,
+            }],
+        });
+
+        assert!(stack.is_ignored(Path::new("/repo/sub/dir/file.txt"), "file.txt", false));
+        // A same-named file outside the frame's own directory must not match.
+        assert!(!stack.is_ignored(Path::new("/repo/other/dir/file.txt"), "file.txt", false));
+    }
+
+    #[test]
+    fn unanchored_pattern_matches_by_file_name_at_any_depth() {
+        let mut stack = IgnoreStack::new();
+        stack.frames.push(Frame {
+            dir: PathBuf::from("/repo"),
+            patterns: vec![IgnorePattern {
+                glob: "*.log".to_string(),
+                negate: false,
+                dir_only: false,
+                anchored: false,
+            }],
+        });
+
+        assert!(stack.is_ignored(Path::new("/repo/a/b/debug.log"), "debug.log", false));
+    }
+
+    #[test]
+    fn parse_line_detects_anchoring_negation_and_dir_only() {
+        let pattern = IgnoreStack::parse_line("!build/").unwrap();
+        assert!(pattern.negate);
+        assert!(pattern.dir_only);
+        assert!(!pattern.anchored);
+        assert_eq!(pattern.glob, "build");
+
+        let pattern = IgnoreStack::parse_line("dir/file.txt").unwrap();
+        assert!(pattern.anchored);
+        assert_eq!(pattern.glob, "dir/file.txt");
+
+        assert!(IgnoreStack::parse_line("# comment").is_none());
+        assert!(IgnoreStack::parse_line("").is_none());
+    }
+}
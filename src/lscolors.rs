@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use std::env;
+
+/// A parsed `LS_COLORS` spec (the same `*.ext=code:di=code:ln=code:...`
+/// format `dircolors` emits), keyed by file-type indicator (`di`, `ln`,
+/// `ex`, ...) and by lowercase extension (`*.tar` -> `tar`).
+pub struct LsColors {
+    types: HashMap<String, String>,
+    extensions: HashMap<String, String>,
+}
+
+impl LsColors {
+    /// Parse `LS_COLORS` from the environment. Returns an empty table
+    /// (callers fall back to the built-in scheme) when it's unset.
+    pub fn from_env() -> Self {
+        match env::var("LS_COLORS") {
+            Ok(spec) => Self::parse(&spec),
+            Err(_) => LsColors { types: HashMap::new(), extensions: HashMap::new() },
+        }
+    }
+
+    fn parse(spec: &str) -> Self {
+        let mut types = HashMap::new();
+        let mut extensions = HashMap::new();
+
+        for entry in spec.split(':') {
+            let Some((key, code)) = entry.split_once('=') else { continue };
+            if code.is_empty() {
+                continue;
+            }
+
+            if let Some(ext) = key.strip_prefix("*.") {
+                extensions.insert(ext.to_lowercase(), code.to_string());
+            } else if let Some(ext) = key.strip_prefix('*') {
+                extensions.insert(ext.to_lowercase(), code.to_string());
+            } else {
+                types.insert(key.to_string(), code.to_string());
+            }
+        }
+
+        LsColors { types, extensions }
+    }
+
+    /// Resolve the SGR code for an entry: its type indicator first (`di`,
+    /// `ln`, `ex`, ...), falling back to its lowercase extension.
+    pub fn style_for(&self, type_key: &str, extension: Option<&str>) -> Option<&str> {
+        if let Some(code) = self.types.get(type_key) {
+            return Some(code);
+        }
+        extension.and_then(|ext| self.extensions.get(&ext.to_lowercase())).map(|s| s.as_str())
+    }
+}
+
+/// Wrap `text` in the given SGR code, e.g. `paint("01;34", "src")`.
+pub fn paint(code: &str, text: &str) -> String {
+    format!("\x1b[{}m{}\x1b[0m", code, text)
+}
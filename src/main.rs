@@ -1,10 +1,17 @@
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use std::io;
 
 // Import our custom modules
+mod gitignore;
+mod lscolors;
 mod organize;
+mod owner;
+mod parallel_walk;
 mod pentest;
 mod search;
+mod tree;
 mod utils;
 
 use organize::OrganizeCommand;
@@ -58,25 +65,37 @@ enum Commands {
         modified_days: Option<u64>,
 
         /// Search recursively (default: true)
-        #[arg(short = 'R', long, default_value = "true")]
+        #[arg(short = 'R', long, default_value = "true", overrides_with = "no_recursive")]
         recursive: bool,
 
+        /// Disable recursive search, overriding an earlier --recursive
+        #[arg(long, hide = true, overrides_with = "recursive")]
+        no_recursive: bool,
+
         /// Maximum depth for recursive search
         #[arg(short = 'd', long)]
         max_depth: Option<usize>,
 
         /// Show hidden files (can combine: -iH or -irH)
-        #[arg(short = 'H', long)]
+        #[arg(short = 'H', long, overrides_with = "no_hidden")]
         hidden: bool,
 
+        /// Hide hidden files, overriding an earlier --hidden
+        #[arg(long, hide = true, overrides_with = "hidden")]
+        no_hidden: bool,
+
         /// Output format (default, json, detailed)
         #[arg(short = 'o', long, default_value = "default")]
         output: String,
 
         /// Execute command on found files
-        #[arg(short = 'x', long)]
+        #[arg(short = 'x', long, group = "exec_mode")]
         exec: Option<String>,
 
+        /// Execute command once with all matches as arguments (like xargs)
+        #[arg(short = 'X', long, group = "exec_mode")]
+        exec_batch: Option<String>,
+
         /// Verbose output (can combine: -vH or -viH)
         #[arg(short = 'v', long)]
         verbose: bool,
@@ -86,8 +105,28 @@ enum Commands {
         quiet: bool,
 
         /// Follow symbolic links (can combine: -iHl)
-        #[arg(short = 'l', long)]
+        #[arg(short = 'l', long, overrides_with = "no_follow_links")]
         follow_links: bool,
+
+        /// Don't follow symbolic links, overriding an earlier --follow-links
+        #[arg(long, hide = true, overrides_with = "follow_links")]
+        no_follow_links: bool,
+
+        /// Don't respect .gitignore/.ignore/.frignore files
+        #[arg(short = 'I', long)]
+        no_ignore: bool,
+
+        /// Don't respect .gitignore (still honors .ignore/.frignore)
+        #[arg(long)]
+        no_ignore_vcs: bool,
+
+        /// Filter by owner, e.g. "user:group", ":staff", "1000:", "!root:"
+        #[arg(long)]
+        owner: Option<String>,
+
+        /// Number of worker threads for traversal (default: available parallelism)
+        #[arg(short = 'j', long)]
+        threads: Option<usize>,
     },
 
     /// Organize files by type, date, or custom rules
@@ -113,13 +152,21 @@ enum Commands {
         copy: bool,
 
         /// Organize recursively (can combine: -rn or -rc)
-        #[arg(short, long)]
+        #[arg(short, long, overrides_with = "no_recursive")]
         recursive: bool,
 
+        /// Disable recursive organizing, overriding an earlier --recursive
+        #[arg(long, hide = true, overrides_with = "recursive")]
+        no_recursive: bool,
+
         /// Include hidden files (can combine: -rH or -nrH)
-        #[arg(short = 'H', long)]
+        #[arg(short = 'H', long, overrides_with = "no_hidden")]
         hidden: bool,
 
+        /// Exclude hidden files, overriding an earlier --hidden
+        #[arg(long, hide = true, overrides_with = "hidden")]
+        no_hidden: bool,
+
         /// Verbose output (can combine: -rvH)
         #[arg(short = 'v', long)]
         verbose: bool,
@@ -132,16 +179,43 @@ enum Commands {
         path: String,
 
         /// Analyze recursively (can combine: -rH or -rv)
-        #[arg(short, long)]
+        #[arg(short, long, overrides_with = "no_recursive")]
         recursive: bool,
 
+        /// Disable recursive analysis, overriding an earlier --recursive
+        #[arg(long, hide = true, overrides_with = "recursive")]
+        no_recursive: bool,
+
         /// Include hidden files (can combine: -rH)
-        #[arg(short = 'H', long)]
+        #[arg(short = 'H', long, overrides_with = "no_hidden")]
         hidden: bool,
 
+        /// Exclude hidden files, overriding an earlier --hidden
+        #[arg(long, hide = true, overrides_with = "hidden")]
+        no_hidden: bool,
+
         /// Verbose output (can combine: -rvH)
         #[arg(short = 'v', long)]
         verbose: bool,
+
+        /// Include a "largest directories" section ranked by recursive size
+        #[arg(long)]
+        total_size: bool,
+    },
+
+    /// Show a disk-usage tree annotated with recursive sizes
+    Tree {
+        /// Directory to analyze
+        #[arg(default_value = ".")]
+        path: String,
+
+        /// Stop printing below this depth (sizes are still summed below it)
+        #[arg(long)]
+        depth: Option<usize>,
+
+        /// Collapse entries smaller than this into an <others> entry (e.g. 1M, 512K, 2G)
+        #[arg(long)]
+        aggr: Option<String>,
     },
 
     /// 🔥 Find SUID binaries (setuid - run as owner)
@@ -161,6 +235,14 @@ enum Commands {
         /// Output results to file
         #[arg(short = 'o', long)]
         output: Option<String>,
+
+        /// Filter by owner, e.g. "user:group", ":staff", "1000:", "!root:"
+        #[arg(long)]
+        owner: Option<String>,
+
+        /// Number of worker threads for traversal (default: available parallelism)
+        #[arg(short = 'j', long)]
+        threads: Option<usize>,
     },
 
     /// 🔥 Find SGID binaries (setgid - run as group)
@@ -180,6 +262,10 @@ enum Commands {
         /// Output results to file
         #[arg(short = 'o', long)]
         output: Option<String>,
+
+        /// Number of worker threads for traversal (default: available parallelism)
+        #[arg(short = 'j', long)]
+        threads: Option<usize>,
     },
 
     /// 🔥 Find world-writable files and directories
@@ -207,6 +293,14 @@ enum Commands {
         /// Output results to file
         #[arg(short = 'o', long)]
         output: Option<String>,
+
+        /// Filter by owner, e.g. "user:group", ":staff", "1000:", "!root:"
+        #[arg(long)]
+        owner: Option<String>,
+
+        /// Number of worker threads for traversal (default: available parallelism)
+        #[arg(short = 'j', long)]
+        threads: Option<usize>,
     },
 
     /// 🔥 Find files with capabilities (Linux capabilities)
@@ -226,6 +320,10 @@ enum Commands {
         /// Output results to file
         #[arg(short = 'o', long)]
         output: Option<String>,
+
+        /// Number of worker threads for traversal (default: available parallelism)
+        #[arg(short = 'j', long)]
+        threads: Option<usize>,
     },
 
     /// 🔥 Find interesting config files (credentials, keys, etc.)
@@ -245,6 +343,10 @@ enum Commands {
         /// Output results to file
         #[arg(short = 'o', long)]
         output: Option<String>,
+
+        /// Number of worker threads for traversal (default: available parallelism)
+        #[arg(short = 'j', long)]
+        threads: Option<usize>,
     },
 
     /// 🔥 Find recently modified files (useful for detecting changes)
@@ -265,6 +367,10 @@ enum Commands {
         #[arg(short = 'v', long)]
         verbose: bool,
 
+        /// Number of worker threads for traversal (default: available parallelism)
+        #[arg(short = 'j', long)]
+        threads: Option<usize>,
+
         /// Output results to file
         #[arg(short = 'o', long)]
         output: Option<String>,
@@ -306,6 +412,57 @@ enum Commands {
         /// Explain permissions in detail (e.g., owner:rw-, group:r--, other:r--)
         #[arg(short = 'e', long)]
         explain_perms: bool,
+
+        /// Force one entry per line instead of a column grid
+        #[arg(short = 'o', long)]
+        oneline: bool,
+
+        /// Sort by size, largest first
+        #[arg(short = 'S', long, group = "sort_mode")]
+        sort_size: bool,
+
+        /// Sort by modification time, newest first
+        #[arg(short = 't', long, group = "sort_mode")]
+        sort_time: bool,
+
+        /// Sort by extension
+        #[arg(short = 'X', long, group = "sort_mode")]
+        sort_extension: bool,
+
+        /// Sort directories first, then symlinks, then regular files
+        #[arg(short = 'K', long, group = "sort_mode")]
+        sort_kind: bool,
+
+        /// Reverse the sort order
+        #[arg(short = 'r', long)]
+        reverse: bool,
+
+        /// Show a directory's recursive on-disk size instead of its raw
+        /// directory-entry size (only affects -l)
+        #[arg(long)]
+        total_size: bool,
+
+        /// Colorize names using LS_COLORS, falling back to the built-in
+        /// scheme when it's unset
+        #[arg(long, value_enum, default_value = "auto")]
+        color: utils::ColorMode,
+
+        /// Append a type indicator (*/@/=/|) to executables, symlinks,
+        /// sockets, and FIFOs (directories already get their own /)
+        #[arg(short = 'F', long)]
+        classify: bool,
+
+        /// How to escape names containing spaces, newlines, or control
+        /// characters
+        #[arg(long, value_enum, default_value = "literal")]
+        quoting_style: utils::QuotingStyle,
+    },
+
+    /// Generate shell completion scripts
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: Shell,
     },
 }
 
@@ -324,13 +481,21 @@ fn main() -> Result<()> {
             max_size,
             modified_days,
             recursive,
+            no_recursive,
             max_depth,
             hidden,
+            no_hidden: _,
             output,
             exec,
+            exec_batch,
             verbose,
             quiet,
             follow_links,
+            no_follow_links: _,
+            no_ignore,
+            no_ignore_vcs,
+            owner,
+            threads,
         } => {
             let search_cmd = SearchCommand {
                 pattern,
@@ -341,14 +506,21 @@ fn main() -> Result<()> {
                 min_size,
                 max_size,
                 modified_days,
-                recursive,
+                // `recursive` defaults to true, so an overriding
+                // --no-recursive resets it to true; honor the flag itself.
+                recursive: recursive && !no_recursive,
                 max_depth,
                 hidden,
                 output,
                 exec,
+                exec_batch,
                 verbose,
                 quiet,
                 follow_links,
+                no_ignore,
+                no_ignore_vcs,
+                threads,
+                owner,
             };
             search_cmd.execute()?;
         }
@@ -360,7 +532,9 @@ fn main() -> Result<()> {
             dry_run,
             copy,
             recursive,
+            no_recursive: _,
             hidden,
+            no_hidden: _,
             verbose,
         } => {
             let organize_cmd = OrganizeCommand {
@@ -379,10 +553,17 @@ fn main() -> Result<()> {
         Commands::Stats {
             path,
             recursive,
+            no_recursive: _,
             hidden,
+            no_hidden: _,
             verbose,
+            total_size,
         } => {
-            utils::show_stats(&path, recursive, hidden, verbose)?;
+            utils::show_stats(&path, recursive, hidden, verbose, total_size)?;
+        }
+
+        Commands::Tree { path, depth, aggr } => {
+            tree::show_tree(&path, depth, aggr)?;
         }
 
         Commands::Suid {
@@ -390,8 +571,10 @@ fn main() -> Result<()> {
             quiet,
             verbose,
             output,
+            owner,
+            threads,
         } => {
-            pentest::find_suid_binaries(&path, quiet, verbose, output)?;
+            pentest::find_suid_binaries(&path, quiet, verbose, output, owner, threads)?;
         }
 
         Commands::Sgid {
@@ -399,8 +582,9 @@ fn main() -> Result<()> {
             quiet,
             verbose,
             output,
+            threads,
         } => {
-            pentest::find_sgid_binaries(&path, quiet, verbose, output)?;
+            pentest::find_sgid_binaries(&path, quiet, verbose, output, threads)?;
         }
 
         Commands::Writable {
@@ -410,8 +594,10 @@ fn main() -> Result<()> {
             dirs_only,
             files_only,
             output,
+            owner,
+            threads,
         } => {
-            pentest::find_writable(&path, quiet, verbose, dirs_only, files_only, output)?;
+            pentest::find_writable(&path, quiet, verbose, dirs_only, files_only, output, owner, threads)?;
         }
 
         Commands::Caps {
@@ -419,8 +605,9 @@ fn main() -> Result<()> {
             quiet,
             verbose,
             output,
+            threads,
         } => {
-            pentest::find_capabilities(&path, quiet, verbose, output)?;
+            pentest::find_capabilities(&path, quiet, verbose, output, threads)?;
         }
 
         Commands::Configs {
@@ -428,8 +615,9 @@ fn main() -> Result<()> {
             quiet,
             verbose,
             output,
+            threads,
         } => {
-            pentest::find_configs(&path, quiet, verbose, output)?;
+            pentest::find_configs(&path, quiet, verbose, output, threads)?;
         }
 
         Commands::Recent {
@@ -437,9 +625,10 @@ fn main() -> Result<()> {
             minutes,
             quiet,
             verbose,
+            threads,
             output,
         } => {
-            pentest::find_recently_modified(&path, minutes, quiet, verbose, output)?;
+            pentest::find_recently_modified(&path, minutes, quiet, verbose, output, threads)?;
         }
 
         Commands::Dn {
@@ -488,8 +677,38 @@ fn main() -> Result<()> {
             recursive,
             human,
             explain_perms,
+            oneline,
+            sort_size,
+            sort_time,
+            sort_extension,
+            sort_kind,
+            reverse,
+            total_size,
+            color,
+            classify,
+            quoting_style,
         } => {
-            utils::list_files(&path, all, long, recursive, human, explain_perms)?;
+            let sort_by = if sort_size {
+                utils::SortBy::Size
+            } else if sort_time {
+                utils::SortBy::ModifiedTime
+            } else if sort_extension {
+                utils::SortBy::Extension
+            } else if sort_kind {
+                utils::SortBy::Kind
+            } else {
+                utils::SortBy::Name
+            };
+            utils::list_files(
+                &path, all, long, recursive, human, explain_perms, oneline, sort_by, reverse, total_size, color,
+                classify, quoting_style,
+            )?;
+        }
+
+        Commands::Completions { shell } => {
+            let mut cmd = Cli::command();
+            let name = cmd.get_name().to_string();
+            clap_complete::generate(shell, &mut cmd, name, &mut io::stdout());
         }
     }
 
@@ -0,0 +1,135 @@
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Local};
+use colored::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The `Organize` command: sorts files in a directory into subfolders by
+/// type, date, size, or extension.
+pub struct OrganizeCommand {
+    pub path: String,
+    pub method: String,
+    pub output: Option<String>,
+    pub dry_run: bool,
+    pub copy: bool,
+    pub recursive: bool,
+    pub hidden: bool,
+    pub verbose: bool,
+}
+
+impl OrganizeCommand {
+    pub fn execute(&self) -> Result<()> {
+        let source_path = Path::new(&self.path);
+
+        if !source_path.exists() {
+            anyhow::bail!("Path does not exist: {}", self.path);
+        }
+
+        let dest_root = match &self.output {
+            Some(output) => PathBuf::from(output),
+            None => source_path.to_path_buf(),
+        };
+
+        println!(
+            "\n{} {} {} {}\n",
+            "Organizing:".bold(),
+            source_path.display().to_string().cyan(),
+            "by".bold(),
+            self.method.cyan()
+        );
+
+        let mut moved = 0u64;
+        self.organize_dir(source_path, &dest_root, &mut moved)?;
+
+        println!(
+            "\n{} {} file(s) {}",
+            "Done:".green().bold(),
+            moved,
+            if self.dry_run { "would be moved" } else { "organized" }
+        );
+
+        Ok(())
+    }
+
+    fn organize_dir(&self, dir: &Path, dest_root: &Path, moved: &mut u64) -> Result<()> {
+        for entry in fs::read_dir(dir)?.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            let file_name = entry.file_name();
+            let name = file_name.to_string_lossy();
+
+            if !self.hidden && name.starts_with('.') {
+                continue;
+            }
+
+            if path.is_dir() {
+                if self.recursive {
+                    self.organize_dir(&path, dest_root, moved)?;
+                }
+                continue;
+            }
+
+            let metadata = entry.metadata()?;
+            let bucket = self.bucket_for(&path, &metadata)?;
+            let target_dir = dest_root.join(bucket);
+            let target = target_dir.join(&*name);
+
+            if self.verbose || self.dry_run {
+                println!("  {} -> {}", path.display(), target.display());
+            }
+
+            if !self.dry_run {
+                fs::create_dir_all(&target_dir)?;
+                if self.copy {
+                    fs::copy(&path, &target)?;
+                } else {
+                    fs::rename(&path, &target)?;
+                }
+            }
+
+            *moved += 1;
+        }
+
+        Ok(())
+    }
+
+    fn bucket_for(&self, path: &Path, metadata: &fs::Metadata) -> Result<String> {
+        let bucket = match self.method.as_str() {
+            "date" => {
+                let modified = metadata.modified()?;
+                let datetime: DateTime<Local> = modified.into();
+                format!("{:04}-{:02}", datetime.year(), datetime.month())
+            }
+            "size" => {
+                let size = metadata.len();
+                match size {
+                    0..=102_400 => "small".to_string(),
+                    102_401..=10_485_760 => "medium".to_string(),
+                    _ => "large".to_string(),
+                }
+            }
+            "extension" => path
+                .extension()
+                .and_then(|s| s.to_str())
+                .unwrap_or("no_extension")
+                .to_lowercase(),
+            _ => {
+                let ext = path
+                    .extension()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("")
+                    .to_lowercase();
+                match ext.as_str() {
+                    "jpg" | "jpeg" | "png" | "gif" | "bmp" | "svg" => "images".to_string(),
+                    "mp4" | "mkv" | "avi" | "mov" => "videos".to_string(),
+                    "mp3" | "wav" | "flac" | "ogg" => "audio".to_string(),
+                    "pdf" | "doc" | "docx" | "txt" | "md" => "documents".to_string(),
+                    "zip" | "tar" | "gz" | "7z" | "rar" => "archives".to_string(),
+                    "" => "other".to_string(),
+                    other => format!("{}_files", other),
+                }
+            }
+        };
+
+        Ok(bucket)
+    }
+}
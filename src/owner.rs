@@ -0,0 +1,102 @@
+use anyhow::{Result, bail};
+
+/// A parsed `--owner` filter, e.g. `user:group`, `:staff`, `1000:`, `!root:`.
+/// Either side of the `user:group` pair may be omitted, and a leading `!`
+/// negates the whole match.
+pub struct OwnerFilter {
+    uid: Option<u32>,
+    gid: Option<u32>,
+    negate: bool,
+}
+
+impl OwnerFilter {
+    pub fn parse(spec: &str) -> Result<Self> {
+        let (negate, spec) = match spec.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, spec),
+        };
+
+        let (user_part, group_part) = match spec.split_once(':') {
+            Some((u, g)) => (u, Some(g)),
+            None => (spec, None),
+        };
+
+        let uid = if user_part.is_empty() {
+            None
+        } else {
+            Some(Self::resolve_uid(user_part)?)
+        };
+
+        let gid = match group_part {
+            None | Some("") => None,
+            Some(g) => Some(Self::resolve_gid(g)?),
+        };
+
+        Ok(OwnerFilter { uid, gid, negate })
+    }
+
+    fn resolve_uid(spec: &str) -> Result<u32> {
+        if let Ok(uid) = spec.parse::<u32>() {
+            return Ok(uid);
+        }
+        match users::get_user_by_name(spec) {
+            Some(user) => Ok(user.uid()),
+            None => bail!("Unknown user: {}", spec),
+        }
+    }
+
+    fn resolve_gid(spec: &str) -> Result<u32> {
+        if let Ok(gid) = spec.parse::<u32>() {
+            return Ok(gid);
+        }
+        match users::get_group_by_name(spec) {
+            Some(group) => Ok(group.gid()),
+            None => bail!("Unknown group: {}", spec),
+        }
+    }
+
+    /// Does `(uid, gid)` satisfy this filter?
+    pub fn matches(&self, uid: u32, gid: u32) -> bool {
+        let is_match = self.uid.map(|u| u == uid).unwrap_or(true)
+            && self.gid.map(|g| g == gid).unwrap_or(true);
+        is_match != self.negate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_numeric_uid_and_gid() {
+        let filter = OwnerFilter::parse("1000:1000").unwrap();
+        assert!(filter.matches(1000, 1000));
+        assert!(!filter.matches(1000, 1001));
+        assert!(!filter.matches(1001, 1000));
+    }
+
+    #[test]
+    fn parse_omitted_side_matches_any() {
+        let uid_only = OwnerFilter::parse("1000:").unwrap();
+        assert!(uid_only.matches(1000, 1));
+        assert!(uid_only.matches(1000, 2));
+        assert!(!uid_only.matches(1001, 1));
+
+        let gid_only = OwnerFilter::parse(":1000").unwrap();
+        assert!(gid_only.matches(1, 1000));
+        assert!(!gid_only.matches(1, 1001));
+    }
+
+    #[test]
+    fn parse_negation_inverts_the_match() {
+        let filter = OwnerFilter::parse("!1000:").unwrap();
+        assert!(!filter.matches(1000, 1));
+        assert!(filter.matches(1001, 1));
+    }
+
+    #[test]
+    fn resolve_unknown_user_or_group_errors() {
+        assert!(OwnerFilter::parse("no-such-user-xyz:").is_err());
+        assert!(OwnerFilter::parse(":no-such-group-xyz").is_err());
+    }
+}
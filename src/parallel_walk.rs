@@ -0,0 +1,133 @@
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A directory entry handed to the caller's filter/visit closures.
+pub struct WalkEntry {
+    pub path: PathBuf,
+    pub file_type: fs::FileType,
+}
+
+/// Work-stealing parallel directory walker: a shared deque of pending
+/// directories is fed by `threads` workers, each `readdir`-ing one directory
+/// at a time, pushing subdirectories back onto the queue and sending
+/// matching entries to a single collector. Mirrors the threaded walk fd
+/// uses for its default traversal.
+pub struct ParallelWalker {
+    threads: usize,
+    follow_links: bool,
+}
+
+impl ParallelWalker {
+    pub fn new(threads: Option<usize>, follow_links: bool) -> Self {
+        let threads = threads
+            .filter(|t| *t > 0)
+            .or_else(|| thread::available_parallelism().ok().map(|n| n.get()))
+            .unwrap_or(4);
+
+        ParallelWalker { threads, follow_links }
+    }
+
+    /// Walk `root`, calling `filter` on every entry from worker threads.
+    /// Entries for which `filter` returns `true` are collected and returned
+    /// sorted by path (matches collect from `rx` in whatever order workers
+    /// happen to finish, so the result is sorted afterward for a stable,
+    /// reproducible order — unlike the unsorted per-worker send order).
+    pub fn walk<F>(&self, root: &Path, filter: F) -> Vec<PathBuf>
+    where
+        F: Fn(&WalkEntry) -> bool + Send + Sync + 'static,
+    {
+        let queue: Arc<Mutex<VecDeque<PathBuf>>> = Arc::new(Mutex::new(VecDeque::from([root.to_path_buf()])));
+        let in_flight = Arc::new(AtomicUsize::new(1));
+        let filter = Arc::new(filter);
+        let (tx, rx) = mpsc::channel::<PathBuf>();
+
+        // Tracks the (device, inode) of the root and of every symlinked
+        // directory queued so far, so a symlink that loops back to an ancestor (directly or through a
+        // longer cycle) is queued at most once instead of hanging the
+        // worker pool and growing the queue forever.
+        let visited: Arc<Mutex<HashSet<(u64, u64)>>> = Arc::new(Mutex::new(HashSet::new()));
+        if let Ok(metadata) = fs::metadata(root) {
+            visited.lock().unwrap().insert((metadata.dev(), metadata.ino()));
+        }
+
+        let mut workers = Vec::with_capacity(self.threads);
+        for _ in 0..self.threads {
+            let queue = Arc::clone(&queue);
+            let in_flight = Arc::clone(&in_flight);
+            let filter = Arc::clone(&filter);
+            let visited = Arc::clone(&visited);
+            let tx = tx.clone();
+            let follow_links = self.follow_links;
+
+            workers.push(thread::spawn(move || loop {
+                let dir = {
+                    let mut queue = queue.lock().unwrap();
+                    queue.pop_front()
+                };
+
+                let Some(dir) = dir else {
+                    if in_flight.load(Ordering::SeqCst) == 0 {
+                        break;
+                    }
+                    thread::yield_now();
+                    continue;
+                };
+
+                if let Ok(read_dir) = fs::read_dir(&dir) {
+                    for entry in read_dir.filter_map(|e| e.ok()) {
+                        let path = entry.path();
+                        let Ok(file_type) = entry.file_type() else { continue };
+
+                        // A symlinked directory can lead back to an
+                        // already-visited one; a plain subdirectory
+                        // can't, so only pay for the extra stat when
+                        // following a symlink.
+                        let first_visit = if file_type.is_symlink() {
+                            follow_links
+                                && match fs::metadata(&path) {
+                                    Ok(metadata) => {
+                                        metadata.is_dir()
+                                            && visited.lock().unwrap().insert((metadata.dev(), metadata.ino()))
+                                    }
+                                    Err(_) => false,
+                                }
+                        } else {
+                            file_type.is_dir()
+                        };
+
+                        if first_visit {
+                            in_flight.fetch_add(1, Ordering::SeqCst);
+                            queue.lock().unwrap().push_back(path.clone());
+                        }
+
+                        let walk_entry = WalkEntry { path: path.clone(), file_type };
+                        if filter(&walk_entry) {
+                            let _ = tx.send(path);
+                        }
+                    }
+                }
+
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+            }));
+        }
+
+        drop(tx);
+
+        // The collector runs on this thread so the caller retains ownership
+        // of stdout ordering; workers only ever send matches over `tx`.
+        let mut matches: Vec<PathBuf> = rx.iter().collect();
+
+        for worker in workers {
+            let _ = worker.join();
+        }
+
+        matches.sort();
+        matches
+    }
+}
@@ -0,0 +1,306 @@
+use crate::owner::OwnerFilter;
+use crate::parallel_walk::ParallelWalker;
+use anyhow::Result;
+use colored::*;
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::os::unix::fs::MetadataExt;
+use std::time::SystemTime;
+
+/// Write matches either to stdout or, if `output` is set, to a file.
+fn emit(lines: &[String], output: Option<&str>) -> Result<()> {
+    match output {
+        Some(path) => {
+            let mut file = File::create(path)?;
+            for line in lines.iter() {
+                writeln!(file, "{}", line)?;
+            }
+            println!("{} {} entries to {}", "Wrote".green().bold(), lines.len(), path);
+        }
+        None => {
+            for line in lines.iter() {
+                println!("{}", line);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn permission_suffix(mode: u32) -> String {
+    format!("{:o}", mode & 0o7777)
+}
+
+/// Find SUID binaries: the setuid bit (04000) is set.
+pub fn find_suid_binaries(
+    path: &str,
+    quiet: bool,
+    verbose: bool,
+    output: Option<String>,
+    owner: Option<String>,
+    threads: Option<usize>,
+) -> Result<()> {
+    if !quiet {
+        println!("\n{} {}\n", "Scanning for SUID binaries:".bold(), path.cyan());
+    }
+
+    let owner_filter = owner.as_deref().map(OwnerFilter::parse).transpose()?;
+
+    let walker = ParallelWalker::new(threads, false);
+    let matches = walker.walk(std::path::Path::new(path), move |entry| {
+        if !entry.file_type.is_file() {
+            return false;
+        }
+        let Ok(metadata) = fs::metadata(&entry.path) else { return false };
+        if metadata.mode() & 0o4000 == 0 {
+            return false;
+        }
+        match &owner_filter {
+            Some(filter) => filter.matches(metadata.uid(), metadata.gid()),
+            None => true,
+        }
+    });
+
+    let lines: Vec<String> = matches
+        .into_iter()
+        .map(|path| {
+            if verbose {
+                let metadata = fs::metadata(&path);
+                let mode = metadata.as_ref().map(|m| m.mode()).unwrap_or(0);
+                let uid = metadata.as_ref().map(|m| m.uid()).unwrap_or(0);
+                format!("{}  {} (uid={})", permission_suffix(mode), path.display(), uid)
+            } else {
+                path.display().to_string()
+            }
+        })
+        .collect();
+
+    emit(&lines, output.as_deref())
+}
+
+/// Find SGID binaries: the setgid bit (02000) is set.
+pub fn find_sgid_binaries(
+    path: &str,
+    quiet: bool,
+    verbose: bool,
+    output: Option<String>,
+    threads: Option<usize>,
+) -> Result<()> {
+    if !quiet {
+        println!("\n{} {}\n", "Scanning for SGID binaries:".bold(), path.cyan());
+    }
+
+    let walker = ParallelWalker::new(threads, false);
+    let matches = walker.walk(std::path::Path::new(path), |entry| {
+        if !entry.file_type.is_file() {
+            return false;
+        }
+        let Ok(metadata) = fs::metadata(&entry.path) else { return false };
+        metadata.mode() & 0o2000 != 0
+    });
+
+    let lines: Vec<String> = matches
+        .into_iter()
+        .map(|path| {
+            if verbose {
+                let metadata = fs::metadata(&path);
+                let mode = metadata.as_ref().map(|m| m.mode()).unwrap_or(0);
+                let gid = metadata.as_ref().map(|m| m.gid()).unwrap_or(0);
+                format!("{}  {} (gid={})", permission_suffix(mode), path.display(), gid)
+            } else {
+                path.display().to_string()
+            }
+        })
+        .collect();
+
+    emit(&lines, output.as_deref())
+}
+
+/// Find world-writable files and/or directories.
+#[allow(clippy::too_many_arguments)]
+pub fn find_writable(
+    path: &str,
+    quiet: bool,
+    verbose: bool,
+    dirs_only: bool,
+    files_only: bool,
+    output: Option<String>,
+    owner: Option<String>,
+    threads: Option<usize>,
+) -> Result<()> {
+    if !quiet {
+        println!("\n{} {}\n", "Scanning for world-writable entries:".bold(), path.cyan());
+    }
+
+    let owner_filter = owner.as_deref().map(OwnerFilter::parse).transpose()?;
+
+    let walker = ParallelWalker::new(threads, false);
+    let matches = walker.walk(std::path::Path::new(path), move |entry| {
+        if dirs_only && !entry.file_type.is_dir() {
+            return false;
+        }
+        if files_only && !entry.file_type.is_file() {
+            return false;
+        }
+        if !entry.file_type.is_dir() && !entry.file_type.is_file() {
+            return false;
+        }
+
+        let Ok(metadata) = fs::metadata(&entry.path) else { return false };
+        if metadata.mode() & 0o0002 == 0 {
+            return false;
+        }
+        match &owner_filter {
+            Some(filter) => filter.matches(metadata.uid(), metadata.gid()),
+            None => true,
+        }
+    });
+
+    let lines: Vec<String> = matches
+        .into_iter()
+        .map(|path| {
+            if verbose {
+                let mode = fs::metadata(&path).map(|m| m.mode()).unwrap_or(0);
+                format!("{}  {}", permission_suffix(mode), path.display())
+            } else {
+                path.display().to_string()
+            }
+        })
+        .collect();
+
+    emit(&lines, output.as_deref())
+}
+
+/// Find files carrying Linux file capabilities (`security.capability` xattr).
+pub fn find_capabilities(
+    path: &str,
+    quiet: bool,
+    verbose: bool,
+    output: Option<String>,
+    threads: Option<usize>,
+) -> Result<()> {
+    if !quiet {
+        println!("\n{} {}\n", "Scanning for file capabilities:".bold(), path.cyan());
+    }
+
+    let walker = ParallelWalker::new(threads, false);
+    let matches = walker.walk(std::path::Path::new(path), |entry| {
+        if !entry.file_type.is_file() {
+            return false;
+        }
+        matches!(xattr::get(&entry.path, "security.capability"), Ok(Some(_)))
+    });
+
+    let lines: Vec<String> = matches
+        .into_iter()
+        .map(|path| {
+            if verbose {
+                format!("{}  (has capabilities)", path.display())
+            } else {
+                path.display().to_string()
+            }
+        })
+        .collect();
+
+    emit(&lines, output.as_deref())
+}
+
+/// Filenames commonly holding credentials, keys, or other secrets.
+const INTERESTING_NAMES: &[&str] = &[
+    ".env", "id_rsa", "id_ed25519", "id_dsa", "credentials", "config.json",
+    ".npmrc", ".pgpass", ".netrc", "shadow", "secrets.yml", "secrets.yaml",
+];
+
+/// Find config files commonly containing credentials or keys.
+pub fn find_configs(
+    path: &str,
+    quiet: bool,
+    verbose: bool,
+    output: Option<String>,
+    threads: Option<usize>,
+) -> Result<()> {
+    if !quiet {
+        println!("\n{} {}\n", "Scanning for interesting config files:".bold(), path.cyan());
+    }
+
+    let walker = ParallelWalker::new(threads, false);
+    let matches = walker.walk(std::path::Path::new(path), |entry| {
+        if !entry.file_type.is_file() {
+            return false;
+        }
+        let file_name = entry
+            .path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        INTERESTING_NAMES.iter().any(|n| file_name == *n)
+            || file_name.ends_with(".pem")
+            || file_name.ends_with(".key")
+    });
+
+    let lines: Vec<String> = matches
+        .into_iter()
+        .map(|path| {
+            if verbose {
+                let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+                format!("{}  ({} bytes)", path.display(), size)
+            } else {
+                path.display().to_string()
+            }
+        })
+        .collect();
+
+    emit(&lines, output.as_deref())
+}
+
+/// Find files modified within the last `minutes` minutes.
+pub fn find_recently_modified(
+    path: &str,
+    minutes: u64,
+    quiet: bool,
+    verbose: bool,
+    output: Option<String>,
+    threads: Option<usize>,
+) -> Result<()> {
+    if !quiet {
+        println!(
+            "\n{} {} ({} minutes)\n",
+            "Scanning for recently modified files:".bold(),
+            path.cyan(),
+            minutes
+        );
+    }
+
+    let window = std::time::Duration::from_secs(minutes * 60);
+
+    let walker = ParallelWalker::new(threads, false);
+    let matches = walker.walk(std::path::Path::new(path), move |entry| {
+        if !entry.file_type.is_file() {
+            return false;
+        }
+        let Ok(metadata) = fs::metadata(&entry.path) else { return false };
+        let Ok(modified) = metadata.modified() else { return false };
+        let Ok(elapsed) = SystemTime::now().duration_since(modified) else { return false };
+        elapsed <= window
+    });
+
+    let lines: Vec<String> = matches
+        .into_iter()
+        .map(|path| {
+            if verbose {
+                let elapsed = fs::metadata(&path)
+                    .ok()
+                    .and_then(|m| m.modified().ok())
+                    .and_then(|m| SystemTime::now().duration_since(m).ok())
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0);
+                format!("{}  ({}s ago)", path.display(), elapsed)
+            } else {
+                path.display().to_string()
+            }
+        })
+        .collect();
+
+    emit(&lines, output.as_deref())
+}
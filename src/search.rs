@@ -0,0 +1,500 @@
+use crate::gitignore::IgnoreStack;
+use crate::owner::OwnerFilter;
+use crate::parallel_walk::ParallelWalker;
+use crate::utils::glob_match_inner;
+use anyhow::Result;
+use colored::*;
+use humansize::{BINARY, format_size};
+use regex::Regex;
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+use std::process::Command as ProcessCommand;
+use std::time::SystemTime;
+use walkdir::WalkDir;
+
+/// The `Find` command: walks a directory tree applying pattern, type, size,
+/// and age filters, with optional execution on every match.
+pub struct SearchCommand {
+    pub pattern: String,
+    pub path: String,
+    pub ignore_case: bool,
+    pub regex: bool,
+    pub file_type: Option<String>,
+    pub min_size: Option<String>,
+    pub max_size: Option<String>,
+    pub modified_days: Option<u64>,
+    pub recursive: bool,
+    pub max_depth: Option<usize>,
+    pub hidden: bool,
+    pub output: String,
+    pub exec: Option<String>,
+    pub exec_batch: Option<String>,
+    pub verbose: bool,
+    pub quiet: bool,
+    pub follow_links: bool,
+    pub no_ignore: bool,
+    pub no_ignore_vcs: bool,
+    pub owner: Option<String>,
+    pub threads: Option<usize>,
+}
+
+impl SearchCommand {
+    pub fn execute(&self) -> Result<()> {
+        let source_path = Path::new(&self.path);
+
+        if !source_path.exists() {
+            anyhow::bail!("Path does not exist: {}", self.path);
+        }
+
+        let matcher = self.build_matcher()?;
+        let owner_filter = self
+            .owner
+            .as_deref()
+            .map(OwnerFilter::parse)
+            .transpose()?;
+
+        let max_depth = if !self.recursive {
+            Some(1)
+        } else {
+            self.max_depth
+        };
+
+        let mut matches: Vec<PathBuf> = Vec::new();
+
+        if self.no_ignore && max_depth.is_none() {
+            // Unrestricted-depth traversal with ignore-checking disabled can
+            // use the fully parallel walker; depth-limited or ignore-aware
+            // walks still need the sequential, stateful walkers below.
+            let hidden = self.hidden;
+            let source_path_owned = source_path.to_path_buf();
+            let walker = ParallelWalker::new(self.threads, self.follow_links);
+            let entries = walker.walk(source_path, move |entry| {
+                if entry.path == source_path_owned {
+                    return false;
+                }
+                if !hidden {
+                    if let Some(name) = entry.path.file_name() {
+                        if name.to_string_lossy().starts_with('.') {
+                            return false;
+                        }
+                    }
+                }
+                true
+            });
+
+            for path in entries {
+                let file_name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                self.consider(&path, &file_name, &matcher, owner_filter.as_ref(), &mut matches);
+            }
+        } else if self.no_ignore {
+            let mut walker = WalkDir::new(source_path).follow_links(self.follow_links);
+            if let Some(depth) = max_depth {
+                walker = walker.max_depth(depth);
+            }
+
+            for entry in walker.into_iter().filter_map(|e| e.ok()) {
+                if entry.path() == source_path {
+                    continue;
+                }
+                if !self.hidden && entry.file_name().to_string_lossy().starts_with('.') {
+                    continue;
+                }
+                self.consider(
+                    entry.path(),
+                    &entry.file_name().to_string_lossy(),
+                    &matcher,
+                    owner_filter.as_ref(),
+                    &mut matches,
+                );
+            }
+        } else {
+            let mut ignore_stack = IgnoreStack::new();
+            self.walk_respecting_ignores(
+                source_path,
+                0,
+                max_depth,
+                &matcher,
+                owner_filter.as_ref(),
+                &mut ignore_stack,
+                &mut matches,
+            )?;
+        }
+
+        self.report(&matches)?;
+
+        if let Some(ref cmd) = self.exec {
+            for path in &matches {
+                self.run_exec(cmd, path)?;
+            }
+        } else if let Some(ref cmd) = self.exec_batch {
+            self.run_exec_batch(cmd, &matches)?;
+        }
+
+        Ok(())
+    }
+
+    /// Recursively walk `dir`, pushing/popping a `.gitignore`/`.ignore`
+    /// frame at each level so matches inherit parent ignore rules.
+    #[allow(clippy::too_many_arguments)]
+    fn walk_respecting_ignores(
+        &self,
+        dir: &Path,
+        depth: usize,
+        max_depth: Option<usize>,
+        matcher: &dyn Fn(&str) -> bool,
+        owner_filter: Option<&OwnerFilter>,
+        ignore_stack: &mut IgnoreStack,
+        matches: &mut Vec<PathBuf>,
+    ) -> Result<()> {
+        ignore_stack.push_dir(dir, !self.no_ignore_vcs, true);
+
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => {
+                ignore_stack.pop();
+                return Ok(());
+            }
+        };
+
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+
+            if !self.hidden && file_name.starts_with('.') {
+                continue;
+            }
+
+            let file_type = match entry.file_type() {
+                Ok(ft) => ft,
+                Err(_) => continue,
+            };
+            let is_dir = file_type.is_dir() && (self.follow_links || !file_type.is_symlink());
+
+            if ignore_stack.is_ignored(&path, &file_name, is_dir) {
+                continue;
+            }
+
+            if is_dir {
+                if max_depth.map(|d| depth + 1 < d).unwrap_or(true) {
+                    self.walk_respecting_ignores(
+                        &path,
+                        depth + 1,
+                        max_depth,
+                        matcher,
+                        owner_filter,
+                        ignore_stack,
+                        matches,
+                    )?;
+                }
+            }
+
+            self.consider(&path, &file_name, matcher, owner_filter, matches);
+        }
+
+        ignore_stack.pop();
+        Ok(())
+    }
+
+    fn consider(
+        &self,
+        path: &Path,
+        file_name: &str,
+        matcher: &dyn Fn(&str) -> bool,
+        owner_filter: Option<&OwnerFilter>,
+        matches: &mut Vec<PathBuf>,
+    ) {
+        if !matcher(file_name) {
+            return;
+        }
+
+        if let Some(ref file_type) = self.file_type {
+            let matches_type = match fs::symlink_metadata(path) {
+                Ok(metadata) => match file_type.as_str() {
+                    "file" => metadata.is_file(),
+                    "dir" => metadata.is_dir(),
+                    "symlink" => metadata.is_symlink(),
+                    _ => true,
+                },
+                Err(_) => return,
+            };
+            if !matches_type {
+                return;
+            }
+        }
+
+        if let Ok(metadata) = fs::metadata(path) {
+            if !self.size_in_range(metadata.len()) {
+                return;
+            }
+
+            if let Some(days) = self.modified_days {
+                if !Self::modified_within(&metadata, days) {
+                    return;
+                }
+            }
+
+            if let Some(filter) = owner_filter {
+                if !filter.matches(metadata.uid(), metadata.gid()) {
+                    return;
+                }
+            }
+        } else if owner_filter.is_some() {
+            return;
+        }
+
+        matches.push(path.to_path_buf());
+    }
+
+    fn build_matcher(&self) -> Result<Box<dyn Fn(&str) -> bool>> {
+        if self.regex {
+            let pattern = if self.ignore_case {
+                format!("(?i){}", self.pattern)
+            } else {
+                self.pattern.clone()
+            };
+            let re = Regex::new(&pattern)?;
+            Ok(Box::new(move |name: &str| re.is_match(name)))
+        } else {
+            let pattern = if self.ignore_case {
+                self.pattern.to_lowercase()
+            } else {
+                self.pattern.clone()
+            };
+            let ignore_case = self.ignore_case;
+            Ok(Box::new(move |name: &str| {
+                let candidate = if ignore_case {
+                    name.to_lowercase()
+                } else {
+                    name.to_string()
+                };
+                glob_match(&pattern, &candidate)
+            }))
+        }
+    }
+
+    fn size_in_range(&self, size: u64) -> bool {
+        if let Some(ref min) = self.min_size {
+            if let Some(min_bytes) = parse_size(min) {
+                if size < min_bytes {
+                    return false;
+                }
+            }
+        }
+        if let Some(ref max) = self.max_size {
+            if let Some(max_bytes) = parse_size(max) {
+                if size > max_bytes {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn modified_within(metadata: &fs::Metadata, days: u64) -> bool {
+        if let Ok(modified) = metadata.modified() {
+            if let Ok(elapsed) = SystemTime::now().duration_since(modified) {
+                return elapsed.as_secs() <= days * 86400;
+            }
+        }
+        false
+    }
+
+    fn run_exec(&self, cmd: &str, path: &Path) -> Result<()> {
+        let quoted_path = shell_quote(&path.display().to_string());
+        let full_cmd = if cmd.contains("{}") {
+            cmd.replace("{}", &quoted_path)
+        } else {
+            format!("{} {}", cmd, quoted_path)
+        };
+
+        if self.verbose {
+            eprintln!("{} {}", "Running:".bold(), full_cmd);
+        }
+
+        let status = ProcessCommand::new("sh").arg("-c").arg(&full_cmd).status()?;
+
+        if !status.success() && self.verbose {
+            eprintln!("{} command exited with {}", "Warning:".yellow(), status);
+        }
+
+        Ok(())
+    }
+
+    /// Invoke `cmd` as few times as possible, batching matched paths as
+    /// trailing arguments (like `xargs`), splitting only when a batch would
+    /// exceed the OS argument-length limit.
+    fn run_exec_batch(&self, cmd: &str, matches: &[PathBuf]) -> Result<()> {
+        if matches.is_empty() {
+            return Ok(());
+        }
+
+        let has_placeholder = cmd.contains("{}");
+        let max_batch_bytes = Self::arg_max_bytes();
+
+        let mut batch: Vec<&PathBuf> = Vec::new();
+        let mut batch_bytes = cmd.len();
+
+        let flush = |batch: &[&PathBuf]| -> Result<()> {
+            if batch.is_empty() {
+                return Ok(());
+            }
+
+            // Single-quote every path so a name containing spaces or shell
+            // metacharacters (`;`, `$(...)`, backticks, ...) is passed
+            // through as one literal argument instead of being re-parsed or
+            // executed as shell code.
+            let joined = batch
+                .iter()
+                .map(|p| shell_quote(&p.display().to_string()))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            let full_cmd = if has_placeholder {
+                cmd.replace("{}", &joined)
+            } else {
+                format!("{} {}", cmd, joined)
+            };
+
+            if self.verbose {
+                eprintln!("{} {}", "Running:".bold(), full_cmd);
+            }
+
+            let status = ProcessCommand::new("sh").arg("-c").arg(&full_cmd).status()?;
+            if !status.success() && self.verbose {
+                eprintln!("{} command exited with {}", "Warning:".yellow(), status);
+            }
+
+            Ok(())
+        };
+
+        for path in matches {
+            // Count the quoted form, since that is what `flush` sends.
+            let entry_bytes = shell_quote(&path.display().to_string()).len() + 1;
+            if !batch.is_empty() && batch_bytes + entry_bytes > max_batch_bytes {
+                flush(&batch)?;
+                batch.clear();
+                batch_bytes = cmd.len();
+            }
+            batch.push(path);
+            batch_bytes += entry_bytes;
+        }
+
+        flush(&batch)
+    }
+
+    /// Conservative approximation of the OS argument-length limit (bytes),
+    /// used to decide when a batch must be split into multiple invocations.
+    /// The whole batch reaches `sh -c` as one argument, and Linux caps a
+    /// single argument at 128 KiB whatever ARG_MAX is, so the result never
+    /// exceeds that cap minus a 4 KiB margin.
+    fn arg_max_bytes() -> usize {
+        const SINGLE_ARG_MAX: usize = 128 * 1024 - 4096;
+        #[cfg(unix)]
+        {
+            let lim = unsafe { libc::sysconf(libc::_SC_ARG_MAX) };
+            if lim > 0 {
+                return (lim as usize).saturating_sub(4096).clamp(4096, SINGLE_ARG_MAX);
+            }
+        }
+        SINGLE_ARG_MAX
+    }
+
+    fn report(&self, matches: &[PathBuf]) -> Result<()> {
+        if self.quiet {
+            for path in matches {
+                println!("{}", path.display());
+            }
+            return Ok(());
+        }
+
+        match self.output.as_str() {
+            "json" => {
+                let items: Vec<String> = matches
+                    .iter()
+                    .map(|p| format!("\"{}\"", p.display()))
+                    .collect();
+                println!("[{}]", items.join(","));
+            }
+            "detailed" => {
+                for path in matches {
+                    let size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+                    println!(
+                        "{}  {}",
+                        path.display().to_string().cyan(),
+                        format_size(size, BINARY).yellow()
+                    );
+                }
+                println!("\n{} {}", "Total matches:".bold(), matches.len());
+            }
+            _ => {
+                for path in matches {
+                    println!("{}", path.display());
+                }
+                if self.verbose {
+                    println!("\n{} {}", "Total matches:".bold(), matches.len());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Single-quote `s` for safe interpolation into a `sh -c` string, escaping
+/// any embedded single quotes so the result is always exactly one shell word
+/// regardless of spaces or metacharacters in `s`.
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+/// Minimal shell-style glob matcher supporting `*` and `?`. A pattern with
+/// no wildcards at all falls back to a plain substring match.
+fn glob_match(pattern: &str, candidate: &str) -> bool {
+    if !pattern.contains('*') && !pattern.contains('?') {
+        return candidate.contains(pattern);
+    }
+    glob_match_inner(pattern.as_bytes(), candidate.as_bytes())
+}
+
+/// Parse a human size string like "1M", "500K", "2G" into bytes.
+pub(crate) fn parse_size(spec: &str) -> Option<u64> {
+    let spec = spec.trim();
+    let (num_part, mult) = match spec.chars().last() {
+        Some('K') | Some('k') => (&spec[..spec.len() - 1], 1024u64),
+        Some('M') | Some('m') => (&spec[..spec.len() - 1], 1024 * 1024),
+        Some('G') | Some('g') => (&spec[..spec.len() - 1], 1024 * 1024 * 1024),
+        _ => (spec, 1),
+    };
+    num_part.trim().parse::<u64>().ok().map(|n| n * mult)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_size_handles_unit_suffixes() {
+        assert_eq!(parse_size("512"), Some(512));
+        assert_eq!(parse_size("1K"), Some(1024));
+        assert_eq!(parse_size("2M"), Some(2 * 1024 * 1024));
+        assert_eq!(parse_size("1g"), Some(1024 * 1024 * 1024));
+    }
+
+    #[test]
+    fn parse_size_trims_whitespace_and_rejects_garbage() {
+        assert_eq!(parse_size("  10K  "), Some(10 * 1024));
+        assert_eq!(parse_size("not-a-size"), None);
+        assert_eq!(parse_size(""), None);
+    }
+
+    #[test]
+    fn shell_quote_escapes_embedded_single_quotes() {
+        assert_eq!(shell_quote("two words.txt"), "'two words.txt'");
+        assert_eq!(shell_quote("it's.txt"), "'it'\\''s.txt'");
+    }
+}
@@ -0,0 +1,206 @@
+use crate::search::parse_size;
+use crate::utils::create_bar;
+use anyhow::Result;
+use colored::*;
+use humansize::{BINARY, format_size};
+use std::fs;
+use std::path::Path;
+
+/// A directory-tree node with its size accumulated bottom-up: a file's size
+/// is its own length; a directory's size is the sum of every child's total
+/// (the directory entry's own length is not counted). This is what makes the rendered tree read like a
+/// flamegraph of disk consumption.
+struct TreeNode {
+    name: String,
+    size: u64,
+    is_dir: bool,
+    children: Vec<TreeNode>,
+}
+
+/// The `tree` command: a `dutree`/`dust`-style disk-usage view, with
+/// optional depth limiting (for display only — sizes are always summed to
+/// the bottom) and aggregation of small entries into a synthetic `<others>`.
+pub fn show_tree(path: &str, depth: Option<usize>, aggr: Option<String>) -> Result<()> {
+    let source_path = Path::new(path);
+
+    if !source_path.exists() {
+        anyhow::bail!("Path does not exist: {}", path);
+    }
+
+    let aggr_threshold = aggr.as_deref().and_then(parse_size);
+
+    println!(
+        "\n{} {}\n",
+        "Disk usage for:".bold(),
+        source_path.display().to_string().cyan()
+    );
+
+    let mut root = build_node(source_path)?;
+    if let Some(threshold) = aggr_threshold {
+        aggregate_small(&mut root, threshold);
+    }
+    sort_children(&mut root);
+
+    print_node(&root, root.size, depth, 0, "");
+
+    println!(
+        "\n{} {}",
+        "Total:".bold(),
+        format_size(root.size, BINARY).cyan()
+    );
+
+    Ok(())
+}
+
+/// Recursively walk `path`, building a tree node whose size is the sum of
+/// its own file size (if any) plus every child's total.
+fn build_node(path: &Path) -> Result<TreeNode> {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string());
+
+    let metadata = fs::symlink_metadata(path)?;
+
+    if !metadata.is_dir() {
+        return Ok(TreeNode {
+            name,
+            size: metadata.len(),
+            is_dir: false,
+            children: Vec::new(),
+        });
+    }
+
+    let mut children = Vec::new();
+    let mut size = 0u64;
+
+    if let Ok(read_dir) = fs::read_dir(path) {
+        for entry in read_dir.filter_map(|e| e.ok()) {
+            if let Ok(child) = build_node(&entry.path()) {
+                size += child.size;
+                children.push(child);
+            }
+        }
+    }
+
+    Ok(TreeNode {
+        name,
+        size,
+        is_dir: true,
+        children,
+    })
+}
+
+/// Sort every level's children by descending size, the same presentation
+/// order as `show_stats`'s largest-files table.
+fn sort_children(node: &mut TreeNode) {
+    node.children.sort_by(|a, b| b.size.cmp(&a.size));
+    for child in &mut node.children {
+        sort_children(child);
+    }
+}
+
+/// Collapse any child whose total falls below `threshold` into a single
+/// synthetic `<others>` entry summing the collapsed children.
+fn aggregate_small(node: &mut TreeNode, threshold: u64) {
+    for child in &mut node.children {
+        aggregate_small(child, threshold);
+    }
+
+    let (small, mut kept): (Vec<TreeNode>, Vec<TreeNode>) = node
+        .children
+        .drain(..)
+        .partition(|child| child.size < threshold);
+
+    if !small.is_empty() {
+        let others_size: u64 = small.iter().map(|c| c.size).sum();
+        kept.push(TreeNode {
+            name: "<others>".to_string(),
+            size: others_size,
+            is_dir: false,
+            children: Vec::new(),
+        });
+    }
+
+    node.children = kept;
+}
+
+/// Render `node` and its children, stopping further *printing* at
+/// `depth_limit` levels below the root (sizes above were already summed
+/// to the bottom regardless of the limit).
+fn print_node(node: &TreeNode, parent_size: u64, depth_limit: Option<usize>, depth: usize, prefix: &str) {
+    if depth > 0 {
+        let bar = create_bar(node.size, parent_size.max(1), 20);
+        let display_name = if node.is_dir {
+            format!("{}/", node.name).cyan().bold()
+        } else {
+            node.name.normal()
+        };
+
+        println!(
+            "{}{} {}  {}",
+            prefix,
+            bar,
+            format_size(node.size, BINARY).yellow(),
+            display_name
+        );
+    } else {
+        println!(
+            "{}  {}",
+            format_size(node.size, BINARY).yellow(),
+            ".".cyan().bold()
+        );
+    }
+
+    if let Some(limit) = depth_limit {
+        if depth >= limit {
+            return;
+        }
+    }
+
+    let child_prefix = format!("{}  ", prefix);
+    for child in &node.children {
+        print_node(child, node.size, depth_limit, depth + 1, &child_prefix);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(name: &str, size: u64) -> TreeNode {
+        TreeNode { name: name.to_string(), size, is_dir: false, children: Vec::new() }
+    }
+
+    #[test]
+    fn aggregate_small_collapses_children_below_threshold() {
+        let mut root = TreeNode {
+            name: "root".to_string(),
+            size: 0,
+            is_dir: true,
+            children: vec![leaf("big.bin", 1000), leaf("tiny1.txt", 10), leaf("tiny2.txt", 20)],
+        };
+
+        aggregate_small(&mut root, 100);
+
+        assert_eq!(root.children.len(), 2);
+        assert!(root.children.iter().any(|c| c.name == "big.bin" && c.size == 1000));
+        let others = root.children.iter().find(|c| c.name == "<others>").unwrap();
+        assert_eq!(others.size, 30);
+    }
+
+    #[test]
+    fn aggregate_small_leaves_node_untouched_when_nothing_is_small() {
+        let mut root = TreeNode {
+            name: "root".to_string(),
+            size: 0,
+            is_dir: true,
+            children: vec![leaf("a.bin", 1000), leaf("b.bin", 2000)],
+        };
+
+        aggregate_small(&mut root, 100);
+
+        assert_eq!(root.children.len(), 2);
+        assert!(root.children.iter().all(|c| c.name != "<others>"));
+    }
+}
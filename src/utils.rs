@@ -1,3 +1,4 @@
+use crate::lscolors::{LsColors, paint};
 use anyhow::Result;
 use colored::*;
 use humansize::{BINARY, format_size};
@@ -7,7 +8,7 @@ use terminal_size::{Width, terminal_size};
 use walkdir::WalkDir;
 
 // Show detailed statistics about a directory
-pub fn show_stats(path: &str, recursive: bool, hidden: bool, verbose: bool) -> Result<()> {
+pub fn show_stats(path: &str, recursive: bool, hidden: bool, verbose: bool, total_size: bool) -> Result<()> {
     let source_path = Path::new(path);
 
     println!(
@@ -29,7 +30,7 @@ pub fn show_stats(path: &str, recursive: bool, hidden: bool, verbose: bool) -> R
 
     let mut total_files = 0u64;
     let mut total_dirs = 0u64;
-    let mut total_size = 0u64;
+    let mut size_sum = 0u64;
     let mut extension_stats: HashMap<String, (u64, u64)> = HashMap::new(); // (count, size)
     let mut size_distribution: HashMap<&str, u64> = HashMap::new();
 
@@ -50,7 +51,7 @@ pub fn show_stats(path: &str, recursive: bool, hidden: bool, verbose: bool) -> R
 
             if let Ok(metadata) = entry.metadata() {
                 let size = metadata.len();
-                total_size += size;
+                size_sum += size;
 
                 // Extension statistics
                 let ext = entry
@@ -84,7 +85,7 @@ pub fn show_stats(path: &str, recursive: bool, hidden: bool, verbose: bool) -> R
     println!("  Total Directories: {}", total_dirs.to_string().cyan());
     println!(
         "  Total Size:        {}\n",
-        format_size(total_size, BINARY).cyan()
+        format_size(size_sum, BINARY).cyan()
     );
 
     // Show size breakdown
@@ -175,12 +176,76 @@ pub fn show_stats(path: &str, recursive: bool, hidden: bool, verbose: bool) -> R
         );
     }
 
+    // Display largest directories (opt-in: requires a recursive walk per entry)
+    if total_size {
+        println!("\n{}", "Finding largest directories...".green().bold());
+
+        let mut cache: HashMap<std::path::PathBuf, u64> = HashMap::new();
+        let mut dir_sizes: Vec<_> = WalkDir::new(source_path)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_dir())
+            .map(|e| {
+                let path = e.path().to_path_buf();
+                let size = dir_total_size(&path, &mut cache);
+                (path, size)
+            })
+            .collect();
+
+        dir_sizes.sort_by(|a, b| b.1.cmp(&a.1));
+
+        println!("  {:<width$} {:>15}", "Directory", "Size", width = path_width);
+        println!(
+            "  {}",
+            "─".repeat(term_width.saturating_sub(2)).bright_black()
+        );
+
+        for (path, size) in dir_sizes.iter().take(10) {
+            let display_path = path
+                .strip_prefix(source_path)
+                .unwrap_or(path)
+                .display()
+                .to_string();
+            let display_path = if display_path.is_empty() { ".".to_string() } else { display_path };
+            println!(
+                "  {:<width$} {:>15}",
+                display_path,
+                format_size(*size, BINARY).yellow(),
+                width = path_width
+            );
+        }
+    }
+
     println!();
     Ok(())
 }
 
+/// Recursively sum the size of every file under `path`, memoizing the
+/// result so a parent directory's walk can reuse a child's already-computed
+/// total instead of re-walking it.
+pub(crate) fn dir_total_size(path: &Path, cache: &mut HashMap<std::path::PathBuf, u64>) -> u64 {
+    if let Some(size) = cache.get(path) {
+        return *size;
+    }
+
+    let mut total = 0u64;
+    if let Ok(read_dir) = std::fs::read_dir(path) {
+        for entry in read_dir.filter_map(|e| e.ok()) {
+            let Ok(metadata) = entry.metadata() else { continue };
+            if metadata.is_dir() {
+                total += dir_total_size(&entry.path(), cache);
+            } else {
+                total += metadata.len();
+            }
+        }
+    }
+
+    cache.insert(path.to_path_buf(), total);
+    total
+}
+
 // Create a simple ASCII bar chart
-fn create_bar(value: u64, max: u64, width: usize) -> String {
+pub(crate) fn create_bar(value: u64, max: u64, width: usize) -> String {
     if max == 0 {
         return String::new();
     }
@@ -195,7 +260,194 @@ fn create_bar(value: u64, max: u64, width: usize) -> String {
     )
 }
 
+/// Field to sort directory listings by, mirroring classic `ls -S/-t/-X`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SortBy {
+    Name,
+    Size,
+    ModifiedTime,
+    Extension,
+    Kind,
+}
+
+/// `--color` mode for directory listings: auto-detect a TTY, always
+/// colorize, or never (so piped output stays plain).
+#[derive(Clone, Copy, clap::ValueEnum)]
+pub enum ColorMode {
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorMode {
+    fn enabled(self) -> bool {
+        match self {
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+            ColorMode::Auto => terminal_size().is_some(),
+        }
+    }
+}
+
+/// Resolve the type indicator (`di`, `ln`, `ex`, `fi`) `LS_COLORS` uses for
+/// `metadata`.
+fn type_key(metadata: &std::fs::Metadata) -> &'static str {
+    if metadata.is_dir() {
+        "di"
+    } else if metadata.is_symlink() {
+        "ln"
+    } else {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            if metadata.permissions().mode() & 0o111 != 0 {
+                return "ex";
+            }
+        }
+        "fi"
+    }
+}
+
+/// Escaping applied to displayed names, mirroring GNU `ls --quoting-style`.
+#[derive(Clone, Copy, clap::ValueEnum)]
+pub enum QuotingStyle {
+    /// Print the name as-is.
+    Literal,
+    /// Single-quote names containing shell metacharacters or whitespace.
+    Shell,
+    /// C-style backslash-escape control bytes.
+    C,
+}
+
+fn needs_shell_quoting(name: &str) -> bool {
+    name.chars().any(|c| {
+        c.is_whitespace()
+            || c.is_control()
+            || matches!(
+                c,
+                '\'' | '"' | '\\' | '$' | '`' | '!' | '*' | '?' | '[' | ']' | '(' | ')' | '{' | '}' | ';' | '&' | '|' | '<' | '>' | '~' | '#'
+            )
+    })
+}
+
+fn quote_name(name: &str, style: QuotingStyle) -> String {
+    match style {
+        QuotingStyle::Literal => name.to_string(),
+        QuotingStyle::Shell => {
+            if needs_shell_quoting(name) {
+                format!("'{}'", name.replace('\'', "'\\''"))
+            } else {
+                name.to_string()
+            }
+        }
+        QuotingStyle::C => {
+            let mut escaped = String::new();
+            for c in name.chars() {
+                match c {
+                    '\n' => escaped.push_str("\\n"),
+                    '\t' => escaped.push_str("\\t"),
+                    '\\' => escaped.push_str("\\\\"),
+                    '"' => escaped.push_str("\\\""),
+                    c if c.is_control() => escaped.push_str(&format!("\\{:03o}", c as u32)),
+                    c => escaped.push(c),
+                }
+            }
+            format!("\"{}\"", escaped)
+        }
+    }
+}
+
+/// The `--classify` type indicator for non-directory entries (directories
+/// always get their own unconditional `/`, handled by the caller).
+fn classify_suffix(metadata: &std::fs::Metadata) -> &'static str {
+    if metadata.is_symlink() {
+        return "@";
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::FileTypeExt;
+        let file_type = metadata.file_type();
+        if file_type.is_socket() {
+            return "=";
+        }
+        if file_type.is_fifo() {
+            return "|";
+        }
+        use std::os::unix::fs::PermissionsExt;
+        if metadata.permissions().mode() & 0o111 != 0 {
+            return "*";
+        }
+    }
+    ""
+}
+
+/// Build the `(plain, styled)` display form of `name` for `metadata`:
+/// quoted per `quoting`, suffixed with a `--classify` type indicator, and
+/// colorized (consulting `ls_colors` first, then the built-in scheme, when
+/// `use_color` is set).
+fn display_entry(
+    name: &str,
+    metadata: &std::fs::Metadata,
+    use_color: bool,
+    ls_colors: &LsColors,
+    classify: bool,
+    quoting: QuotingStyle,
+) -> (String, String) {
+    let key = type_key(metadata);
+    let extension = if key == "fi" {
+        Path::new(name).extension().and_then(|s| s.to_str())
+    } else {
+        None
+    };
+
+    let quoted = quote_name(name, quoting);
+    let suffix = if metadata.is_dir() {
+        "/"
+    } else if classify {
+        classify_suffix(metadata)
+    } else {
+        ""
+    };
+    let plain = format!("{}{}", quoted, suffix);
+
+    if !use_color {
+        return (plain.clone(), plain);
+    }
+
+    let styled = if let Some(code) = ls_colors.style_for(key, extension) {
+        format!("{}{}", paint(code, &quoted), suffix)
+    } else {
+        match key {
+            "di" => format!("{}{}", quoted.cyan().bold(), suffix),
+            "ln" => format!("{}{}", quoted.purple(), suffix),
+            "ex" => format!("{}{}", quoted.green().bold(), suffix),
+            _ => plain.clone(),
+        }
+    };
+
+    (plain, styled)
+}
+
+/// Rendering/listing preferences shared by `list_directory`, `list_recursive`,
+/// and `print_long_entry` — grouped so a new `ls` flag is one new field here
+/// instead of another positional parameter threaded through all three.
+struct ListOptions<'a> {
+    show_all: bool,
+    long_format: bool,
+    human_readable: bool,
+    explain_perms: bool,
+    oneline: bool,
+    sort_by: SortBy,
+    reverse: bool,
+    total_size: bool,
+    use_color: bool,
+    ls_colors: &'a LsColors,
+    classify: bool,
+    quoting: QuotingStyle,
+}
+
 /// List files in a directory (ls command)
+#[allow(clippy::too_many_arguments)]
 pub fn list_files(
     path: &str,
     show_all: bool,
@@ -203,6 +455,13 @@ pub fn list_files(
     recursive: bool,
     human_readable: bool,
     explain_perms: bool,
+    oneline: bool,
+    sort_by: SortBy,
+    reverse: bool,
+    total_size: bool,
+    color: ColorMode,
+    classify: bool,
+    quoting: QuotingStyle,
 ) -> Result<()> {
     use std::path::Path;
 
@@ -212,94 +471,183 @@ pub fn list_files(
         anyhow::bail!("Path does not exist: {}", path);
     }
 
+    let mut dir_size_cache: HashMap<std::path::PathBuf, u64> = HashMap::new();
+    let ls_colors = LsColors::from_env();
+    let opts = ListOptions {
+        show_all,
+        long_format,
+        human_readable,
+        explain_perms,
+        oneline,
+        sort_by,
+        reverse,
+        total_size,
+        use_color: color.enabled(),
+        ls_colors: &ls_colors,
+        classify,
+        quoting,
+    };
+
     if recursive {
-        list_recursive(source_path, show_all, long_format, human_readable, explain_perms, 0)?;
+        list_recursive(source_path, &opts, &mut dir_size_cache, 0)?;
     } else {
-        list_directory(source_path, show_all, long_format, human_readable, explain_perms)?;
+        list_directory(source_path, &opts, &mut dir_size_cache)?;
     }
 
     Ok(())
 }
 
-fn list_directory(
-    path: &Path,
-    show_all: bool,
-    long_format: bool,
-    human_readable: bool,
-    explain_perms: bool,
-) -> Result<()> {
+/// Read `dir`'s entries, stat each one exactly once, and sort the
+/// (entry, metadata) pairs by `sort_by`/`reverse` so sorting and the
+/// short-format listers never re-stat (`print_long_entry` still stats each
+/// path it prints).
+fn sorted_entries(dir: &Path, sort_by: SortBy, reverse: bool) -> Result<Vec<(std::fs::DirEntry, std::fs::Metadata)>> {
     use std::fs;
 
+    let mut entries: Vec<(fs::DirEntry, fs::Metadata)> = fs::read_dir(dir)?
+        .filter_map(|e| e.ok())
+        .filter_map(|e| {
+            let metadata = e.metadata().ok()?;
+            Some((e, metadata))
+        })
+        .collect();
+
+    entries.sort_by(|a, b| compare_entries(a, b, sort_by));
+    if reverse {
+        entries.reverse();
+    }
+
+    Ok(entries)
+}
+
+fn compare_entries(
+    a: &(std::fs::DirEntry, std::fs::Metadata),
+    b: &(std::fs::DirEntry, std::fs::Metadata),
+    sort_by: SortBy,
+) -> std::cmp::Ordering {
+    match sort_by {
+        SortBy::Name => a.0.file_name().cmp(&b.0.file_name()),
+        SortBy::Size => b.1.len().cmp(&a.1.len()).then_with(|| a.0.file_name().cmp(&b.0.file_name())),
+        SortBy::ModifiedTime => {
+            let a_time = a.1.modified().unwrap_or(std::time::SystemTime::UNIX_EPOCH);
+            let b_time = b.1.modified().unwrap_or(std::time::SystemTime::UNIX_EPOCH);
+            b_time.cmp(&a_time).then_with(|| a.0.file_name().cmp(&b.0.file_name()))
+        }
+        SortBy::Extension => {
+            let a_ext = extension_of(&a.0);
+            let b_ext = extension_of(&b.0);
+            a_ext.cmp(&b_ext).then_with(|| a.0.file_name().cmp(&b.0.file_name()))
+        }
+        SortBy::Kind => kind_rank(&a.1).cmp(&kind_rank(&b.1)).then_with(|| a.0.file_name().cmp(&b.0.file_name())),
+    }
+}
+
+fn extension_of(entry: &std::fs::DirEntry) -> String {
+    entry
+        .path()
+        .extension()
+        .and_then(|s| s.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+}
+
+/// Sort order for `SortBy::Kind`: directories, then symlinks, then files.
+fn kind_rank(metadata: &std::fs::Metadata) -> u8 {
+    if metadata.is_dir() {
+        0
+    } else if metadata.is_symlink() {
+        1
+    } else {
+        2
+    }
+}
+
+/// Render entries (each already colorized, paired with its plain-text form,
+/// whose byte length is taken as its width) as an `ls`-style column-major
+/// grid sized to `term_width`.
+/// Falls back to one entry per line when output isn't a TTY or `oneline`
+/// was requested.
+fn print_grid(entries: &[(String, String)], oneline: bool, indent: &str) {
+    if entries.is_empty() {
+        return;
+    }
+
+    let is_tty = terminal_size().is_some();
+    if oneline || !is_tty {
+        for (_, styled) in entries {
+            println!("{}{}", indent, styled);
+        }
+        return;
+    }
+
+    let term_width = terminal_size()
+        .map(|(Width(w), _)| w as usize)
+        .unwrap_or(80)
+        .saturating_sub(indent.len());
+
+    let max_name_len = entries.iter().map(|(plain, _)| plain.len()).max().unwrap_or(0);
+    let col_width = max_name_len + 2;
+    let cols = (term_width / col_width).max(1);
+    let rows = entries.len().div_ceil(cols);
+
+    for row in 0..rows {
+        let mut line = String::new();
+        for col in 0..cols {
+            let idx = col * rows + row;
+            let Some((plain, styled)) = entries.get(idx) else { continue };
+            line.push_str(styled);
+            if col + 1 < cols && (idx + rows) < entries.len() {
+                line.push_str(&" ".repeat(col_width - plain.len()));
+            }
+        }
+        println!("{}{}", indent, line);
+    }
+}
+
+fn list_directory(path: &Path, opts: &ListOptions, dir_size_cache: &mut HashMap<std::path::PathBuf, u64>) -> Result<()> {
     if path.is_file() {
-        if long_format {
-            print_long_entry(path, human_readable, explain_perms)?;
+        if opts.long_format {
+            print_long_entry(path, opts, dir_size_cache)?;
         } else {
             println!("{}", path.display());
         }
         return Ok(());
     }
 
-    let mut entries: Vec<_> = fs::read_dir(path)?
-        .filter_map(|e| e.ok())
-        .collect();
+    let entries = sorted_entries(path, opts.sort_by, opts.reverse)?;
 
-    entries.sort_by_key(|e| e.file_name());
+    let mut grid_entries: Vec<(String, String)> = Vec::new();
 
-    for entry in entries {
+    for (entry, metadata) in entries {
         let file_name = entry.file_name();
         let name = file_name.to_string_lossy();
 
         // Skip hidden files unless -a flag
-        if !show_all && name.starts_with('.') {
+        if !opts.show_all && name.starts_with('.') {
             continue;
         }
 
-        if long_format {
-            print_long_entry(&entry.path(), human_readable, explain_perms)?;
+        if opts.long_format {
+            print_long_entry(&entry.path(), opts, dir_size_cache)?;
         } else {
-            let metadata = entry.metadata()?;
-            if metadata.is_dir() {
-                println!("{}/", name.cyan().bold());
-            } else if metadata.is_symlink() {
-                println!("{}", name.purple());
-            } else {
-                #[cfg(unix)]
-                {
-                    use std::os::unix::fs::PermissionsExt;
-                    let mode = metadata.permissions().mode();
-                    if mode & 0o111 != 0 {
-                        println!("{}", name.green().bold());
-                    } else {
-                        println!("{}", name);
-                    }
-                }
-                #[cfg(not(unix))]
-                {
-                    println!("{}", name);
-                }
-            }
+            grid_entries.push(display_entry(&name, &metadata, opts.use_color, opts.ls_colors, opts.classify, opts.quoting));
         }
     }
 
+    if !opts.long_format {
+        print_grid(&grid_entries, opts.oneline, "");
+    }
+
     Ok(())
 }
 
-fn list_recursive(
-    path: &Path,
-    show_all: bool,
-    long_format: bool,
-    human_readable: bool,
-    explain_perms: bool,
-    depth: usize,
-) -> Result<()> {
-    use std::fs;
-
+fn list_recursive(path: &Path, opts: &ListOptions, dir_size_cache: &mut HashMap<std::path::PathBuf, u64>, depth: usize) -> Result<()> {
     let indent = "  ".repeat(depth);
 
     if path.is_file() {
-        if long_format {
+        if opts.long_format {
             print!("{}", indent);
-            print_long_entry(path, human_readable, explain_perms)?;
+            print_long_entry(path, opts, dir_size_cache)?;
         } else {
             println!("{}{}", indent, path.file_name().unwrap().to_string_lossy());
         }
@@ -316,58 +664,44 @@ fn list_recursive(
         );
     }
 
-    let mut entries: Vec<_> = fs::read_dir(path)?
-        .filter_map(|e| e.ok())
-        .collect();
+    let entries = sorted_entries(path, opts.sort_by, opts.reverse)?;
 
-    entries.sort_by_key(|e| e.file_name());
+    let mut grid_entries: Vec<(String, String)> = Vec::new();
+    let mut subdirs: Vec<std::path::PathBuf> = Vec::new();
 
-    for entry in entries {
+    for (entry, metadata) in entries {
         let file_name = entry.file_name();
         let name = file_name.to_string_lossy();
 
         // Skip hidden files unless -a flag
-        if !show_all && name.starts_with('.') {
+        if !opts.show_all && name.starts_with('.') {
             continue;
         }
 
-        let metadata = entry.metadata()?;
-
-        if long_format {
+        if opts.long_format {
             print!("{}", indent);
-            print_long_entry(&entry.path(), human_readable, explain_perms)?;
+            print_long_entry(&entry.path(), opts, dir_size_cache)?;
         } else {
-            if metadata.is_dir() {
-                println!("{}{}/", indent, name.cyan().bold());
-            } else if metadata.is_symlink() {
-                println!("{}{}", indent, name.purple());
-            } else {
-                #[cfg(unix)]
-                {
-                    use std::os::unix::fs::PermissionsExt;
-                    let mode = metadata.permissions().mode();
-                    if mode & 0o111 != 0 {
-                        println!("{}{}", indent, name.green().bold());
-                    } else {
-                        println!("{}{}", indent, name);
-                    }
-                }
-                #[cfg(not(unix))]
-                {
-                    println!("{}{}", indent, name);
-                }
-            }
+            grid_entries.push(display_entry(&name, &metadata, opts.use_color, opts.ls_colors, opts.classify, opts.quoting));
         }
 
         if metadata.is_dir() {
-            list_recursive(&entry.path(), show_all, long_format, human_readable, explain_perms, depth + 1)?;
+            subdirs.push(entry.path());
         }
     }
 
+    if !opts.long_format {
+        print_grid(&grid_entries, opts.oneline, &indent);
+    }
+
+    for subdir in subdirs {
+        list_recursive(&subdir, opts, dir_size_cache, depth + 1)?;
+    }
+
     Ok(())
 }
 
-fn print_long_entry(path: &Path, human_readable: bool, explain_perms: bool) -> Result<()> {
+fn print_long_entry(path: &Path, opts: &ListOptions, dir_size_cache: &mut HashMap<std::path::PathBuf, u64>) -> Result<()> {
     use chrono::{DateTime, Local};
     use std::fs;
 
@@ -380,7 +714,7 @@ fn print_long_entry(path: &Path, human_readable: bool, explain_perms: bool) -> R
         use std::os::unix::fs::PermissionsExt;
         let mode = metadata.permissions().mode();
         let perm_str = format_permissions(mode);
-        if explain_perms {
+        if opts.explain_perms {
             let perm_explain = explain_permissions(mode);
             print!("{} {} ", perm_str, perm_explain.bright_black());
         } else {
@@ -389,7 +723,7 @@ fn print_long_entry(path: &Path, human_readable: bool, explain_perms: bool) -> R
     }
     #[cfg(not(unix))]
     {
-        if explain_perms {
+        if opts.explain_perms {
             if metadata.is_dir() {
                 print!("drwxr-xr-x (owner:rwx, group:r-x, other:r-x) ");
             } else {
@@ -404,9 +738,14 @@ fn print_long_entry(path: &Path, human_readable: bool, explain_perms: bool) -> R
         }
     }
 
-    // Size
-    let size = metadata.len();
-    if human_readable {
+    // Size: a directory's own metadata length is meaningless, so with
+    // --total-size substitute the recursive on-disk size of its subtree.
+    let size = if opts.total_size && metadata.is_dir() {
+        dir_total_size(path, dir_size_cache)
+    } else {
+        metadata.len()
+    };
+    if opts.human_readable {
         print!("{:>8} ", format_size(size, BINARY).cyan());
     } else {
         print!("{:>10} ", size.to_string().cyan());
@@ -420,27 +759,11 @@ fn print_long_entry(path: &Path, human_readable: bool, explain_perms: bool) -> R
         print!("            ");
     }
 
-    // Name
-    if metadata.is_dir() {
-        println!("{}/", file_name.cyan().bold());
-    } else if metadata.is_symlink() {
-        println!("{}", file_name.purple());
-    } else {
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let mode = metadata.permissions().mode();
-            if mode & 0o111 != 0 {
-                println!("{}", file_name.green().bold());
-            } else {
-                println!("{}", file_name);
-            }
-        }
-        #[cfg(not(unix))]
-        {
-            println!("{}", file_name);
-        }
-    }
+    // Name: classified and colored from the entry itself, not a symlink's
+    // target, so symlinks get their `ln` style and `@` indicator.
+    let name_metadata = fs::symlink_metadata(path).unwrap_or_else(|_| metadata.clone());
+    let (_, styled) = display_entry(&file_name, &name_metadata, opts.use_color, opts.ls_colors, opts.classify, opts.quoting);
+    println!("{}", styled);
 
     Ok(())
 }
@@ -505,6 +828,22 @@ fn explain_permissions(mode: u32) -> String {
     format!("(owner:{}, group:{}, other:{})", user, group, other)
 }
 
+/// Recursive core of a shell-style `*`/`?` glob match, shared by
+/// `gitignore`'s and `search`'s glob matchers (which differ only in how
+/// they treat a pattern with no wildcards at all).
+pub(crate) fn glob_match_inner(pattern: &[u8], text: &[u8]) -> bool {
+    match (pattern.first(), text.first()) {
+        (None, None) => true,
+        (Some(b'*'), _) => {
+            glob_match_inner(&pattern[1..], text)
+                || (!text.is_empty() && glob_match_inner(pattern, &text[1..]))
+        }
+        (Some(b'?'), Some(_)) => glob_match_inner(&pattern[1..], &text[1..]),
+        (Some(p), Some(t)) if p == t => glob_match_inner(&pattern[1..], &text[1..]),
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -514,4 +853,14 @@ mod tests {
         let bar = create_bar(50, 100, 10);
         assert_eq!(bar.chars().filter(|c| *c == '█').count(), 5);
     }
+
+    #[test]
+    fn glob_match_inner_handles_star_and_question_mark() {
+        assert!(glob_match_inner(b"*.rs", b"main.rs"));
+        assert!(!glob_match_inner(b"*.rs", b"main.rs.bak"));
+        assert!(glob_match_inner(b"fil?.txt", b"file.txt"));
+        assert!(!glob_match_inner(b"fil?.txt", b"fil.txt"));
+        assert!(glob_match_inner(b"", b""));
+        assert!(!glob_match_inner(b"", b"x"));
+    }
 }